@@ -1,11 +1,82 @@
+use std::cell::RefCell;
 use std::collections::HashMap;
 
 use super::{Builtin, GLOBAL_FUNCTIONS};
 use crate::common::QuoteKind;
+use crate::error::SassResult;
 use crate::scope::global_var_exists;
 use crate::unit::Unit;
 use crate::value::{SassFunction, Value};
 
+thread_local! {
+    /// Functions registered for the current compilation via
+    /// `Options::add_function`. Unlike `GLOBAL_FUNCTIONS`, this is not a
+    /// `lazy_static`, since its contents are seeded per-compilation by the
+    /// embedder rather than fixed at compile time.
+    pub(crate) static CUSTOM_FUNCTIONS: RefCell<HashMap<String, SassFunction>> =
+        RefCell::new(HashMap::new());
+}
+
+/// The set of functions exported by a module loaded through `@use`, keyed
+/// by the name under which it is exported.
+///
+/// `SassFunction::Custom` wraps an `Rc<dyn Fn(..)>`, which has no `Debug`
+/// impl, so `Module` can't derive it either.
+#[derive(Default)]
+pub(crate) struct Module {
+    functions: HashMap<String, SassFunction>,
+}
+
+impl Module {
+    pub fn new(functions: HashMap<String, SassFunction>) -> Self {
+        Self { functions }
+    }
+
+    pub fn get_function(&self, name: &str) -> Option<SassFunction> {
+        self.functions.get(name).cloned()
+    }
+}
+
+/// Builds the modules provided by the compiler itself, as opposed to ones
+/// loaded from a user's `@use` rule. Currently this is just `sass:meta`,
+/// since it is the only builtin module implemented in this tree. Modules
+/// loaded by a user's `@use` rule are not added to `MODULES`.
+fn builtin_modules() -> HashMap<String, Module> {
+    let mut builtins = HashMap::new();
+    register(&mut builtins);
+
+    let functions = builtins
+        .into_iter()
+        .map(|(name, builtin)| (name.clone(), SassFunction::Builtin(builtin, name)))
+        .collect();
+
+    let mut modules = HashMap::new();
+    modules.insert("meta".to_owned(), Module::new(functions));
+    modules
+}
+
+thread_local! {
+    /// Modules available to the current compilation, keyed by the
+    /// namespace they were loaded under (e.g. `math` for `@use "sass:math"`).
+    /// Pre-populated with the compiler's own builtin modules; nothing in
+    /// this crate inserts the modules that a stylesheet's `@use` loads.
+    pub(crate) static MODULES: RefCell<HashMap<String, Module>> =
+        RefCell::new(builtin_modules());
+}
+
+fn get_module_function(module: &str, name: &str) -> SassResult<SassFunction> {
+    MODULES.with(|modules| {
+        let modules = modules.borrow();
+        let module = modules
+            .get(module)
+            .ok_or_else(|| format!("There is no module with the namespace \"{}\".", module))?;
+        module
+            .get_function(name)
+            .ok_or_else(|| "Undefined function.".to_owned())
+            .map_err(Into::into)
+    })
+}
+
 pub(crate) fn register(f: &mut HashMap<String, Builtin>) {
     f.insert(
         "if".to_owned(),
@@ -121,7 +192,9 @@ pub(crate) fn register(f: &mut HashMap<String, Builtin>) {
             max_args!(args, 2);
             match arg!(args, scope, super_selector, 0, "name") {
                 Value::Ident(s, _) => Ok(Value::bool(
-                    scope.fn_exists(&s) || GLOBAL_FUNCTIONS.contains_key(&s),
+                    scope.fn_exists(&s)
+                        || GLOBAL_FUNCTIONS.contains_key(&s)
+                        || CUSTOM_FUNCTIONS.with(|f| f.borrow().contains_key(&s)),
                 )),
                 v => Err(format!("$name: {} is not a string.", v).into()),
             }
@@ -146,11 +219,18 @@ pub(crate) fn register(f: &mut HashMap<String, Builtin>) {
                 return Err("$css and $module may not both be passed at once.".into());
             }
 
+            if let Some(module) = module {
+                return Ok(Value::Function(get_module_function(&module, &name)?));
+            }
+
             let func = match scope.get_fn(&name) {
                 Ok(f) => SassFunction::UserDefined(Box::new(f), name),
                 Err(..) => match GLOBAL_FUNCTIONS.get(&name) {
                     Some(f) => SassFunction::Builtin(f.clone(), name),
-                    None => return Err(format!("Function not found: {}", name).into()),
+                    None => match CUSTOM_FUNCTIONS.with(|f| f.borrow().get(&name).cloned()) {
+                        Some(f) => f,
+                        None => return Err(format!("Function not found: {}", name).into()),
+                    },
                 },
             };
 
@@ -168,3 +248,54 @@ pub(crate) fn register(f: &mut HashMap<String, Builtin>) {
         }),
     );
 }
+
+#[cfg(test)]
+mod module_tests {
+    use std::rc::Rc;
+
+    use super::*;
+
+    fn dummy_function(name: &str) -> SassFunction {
+        SassFunction::Custom(Rc::new(|_, _, _| Ok(Value::True)), name.to_owned())
+    }
+
+    #[test]
+    fn errors_when_namespace_is_unknown() {
+        let err = get_module_function("missing", "foo").unwrap_err().to_string();
+        assert!(err.contains("no module with the namespace \"missing\""));
+    }
+
+    #[test]
+    fn errors_when_function_is_undefined_in_module() {
+        MODULES.with(|modules| {
+            modules
+                .borrow_mut()
+                .insert("empty-module".to_owned(), Module::new(HashMap::new()));
+        });
+
+        let err = get_module_function("empty-module", "foo")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Undefined function"));
+    }
+
+    #[test]
+    fn resolves_function_exported_by_module() {
+        let mut functions = HashMap::new();
+        functions.insert("foo".to_owned(), dummy_function("foo"));
+        MODULES.with(|modules| {
+            modules
+                .borrow_mut()
+                .insert("math-like".to_owned(), Module::new(functions));
+        });
+
+        let func = get_module_function("math-like", "foo").unwrap();
+        assert_eq!(func.name(), "foo");
+    }
+
+    #[test]
+    fn resolves_a_real_function_from_the_builtin_meta_module() {
+        let func = get_module_function("meta", "if").unwrap();
+        assert_eq!(func.name(), "if");
+    }
+}
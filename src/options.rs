@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::args::CallArgs;
+use crate::builtin::meta::CUSTOM_FUNCTIONS;
+use crate::error::SassResult;
+use crate::scope::Scope;
+use crate::selector::Selector;
+use crate::value::{SassFunction, Value};
+
+/// Configuration for a single Sass compilation.
+///
+/// Options that affect the embedder-facing API, such as registering custom
+/// functions, are collected here rather than threaded through every parsing
+/// function individually.
+#[derive(Default, Clone)]
+pub struct Options<'a> {
+    custom_fns: HashMap<String, SassFunction>,
+    // `Options` holds no other settings; this only carries the `'a`
+    // lifetime parameter.
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> Options<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a Rust closure as a Sass function under `name`, following
+    /// the same native-function registration model as embeddable scripting
+    /// engines (e.g. Rhai's `Engine::register_fn`).
+    ///
+    /// The registered function behaves exactly like a builtin: it is found
+    /// by `function-exists()` and `get-function()`, and may be invoked
+    /// directly or via `call()`. It is only visible while this `Options` is
+    /// actually compiling something; see `install_custom_functions`.
+    pub fn add_function<F>(mut self, name: impl Into<String>, func: F) -> Self
+    where
+        F: Fn(CallArgs, &Scope, &Selector) -> SassResult<Value> + 'static,
+    {
+        let name = name.into();
+        self.custom_fns
+            .insert(name.clone(), SassFunction::Custom(Rc::new(func), name));
+        self
+    }
+
+    /// Makes this `Options`'s custom functions visible to `function-exists`,
+    /// `get-function`, and `call` for the duration of one compilation.
+    ///
+    /// The compile entry point calls this immediately before parsing and
+    /// keeps the returned guard alive until that compilation finishes, so
+    /// that unrelated compilations on the same thread (or a later reuse of
+    /// the same thread with a different `Options`) never observe functions
+    /// registered here.
+    pub(crate) fn install_custom_functions(&self) -> CustomFunctionsGuard {
+        let names = self.custom_fns.keys().cloned().collect();
+        CUSTOM_FUNCTIONS.with(|functions| {
+            functions.borrow_mut().extend(
+                self.custom_fns
+                    .iter()
+                    .map(|(name, func)| (name.clone(), func.clone())),
+            );
+        });
+        CustomFunctionsGuard { names }
+    }
+}
+
+/// Removes the custom functions it was created for from `CUSTOM_FUNCTIONS`
+/// once dropped, returned by `Options::install_custom_functions`.
+pub(crate) struct CustomFunctionsGuard {
+    names: Vec<String>,
+}
+
+impl Drop for CustomFunctionsGuard {
+    fn drop(&mut self) {
+        CUSTOM_FUNCTIONS.with(|functions| {
+            let mut functions = functions.borrow_mut();
+            for name in &self.names {
+                functions.remove(name);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_functions_are_only_visible_while_the_guard_is_held() {
+        let options = Options::new().add_function("host-fn", |_, _, _| Ok(Value::True));
+
+        assert!(!CUSTOM_FUNCTIONS.with(|f| f.borrow().contains_key("host-fn")));
+
+        {
+            let _guard = options.install_custom_functions();
+            assert!(CUSTOM_FUNCTIONS.with(|f| f.borrow().contains_key("host-fn")));
+        }
+
+        assert!(!CUSTOM_FUNCTIONS.with(|f| f.borrow().contains_key("host-fn")));
+    }
+}
@@ -19,7 +19,6 @@ pub(crate) fn peek_escape(toks: &mut Lexer) -> SassResult<String> {
         Some(t) => t,
         None => return Ok(String::new()),
     };
-    let mut span = first.pos;
     if first.kind == '\n' {
         return Err(("Expected escape sequence.", first.pos()).into());
     } else if first.kind.is_ascii_hexdigit() {
@@ -33,7 +32,6 @@ pub(crate) fn peek_escape(toks: &mut Lexer) -> SassResult<String> {
             }
             value *= 16;
             value += as_hex(next.kind);
-            span = span.merge(next.pos);
             toks.peek_forward(1);
         }
         if toks.peek().is_some() && toks.peek().unwrap().kind.is_whitespace() {
@@ -44,7 +42,14 @@ pub(crate) fn peek_escape(toks: &mut Lexer) -> SassResult<String> {
         toks.advance_cursor();
     }
 
-    let c = std::char::from_u32(value).ok_or(("Invalid escape sequence.", span))?;
+    // The escape is zero, a surrogate, or above the maximum code point, all
+    // of which are disallowed by the CSS Syntax spec's "consume an escaped
+    // code point" algorithm. Emit U+FFFD rather than erroring so we accept
+    // the same escapes browsers do.
+    let c = match std::char::from_u32(value) {
+        Some(c) if value != 0 => c,
+        _ => '\u{FFFD}',
+    };
     if is_name(c) {
         Ok(c.to_string())
     } else if value <= 0x1F || value == 0x7F {
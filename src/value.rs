@@ -0,0 +1,116 @@
+use std::fmt;
+use std::rc::Rc;
+
+use crate::args::CallArgs;
+use crate::builtin::Builtin;
+use crate::common::QuoteKind;
+use crate::error::SassResult;
+use crate::parse::Function;
+use crate::scope::Scope;
+use crate::selector::Selector;
+use crate::unit::Unit;
+
+/// A numeric SassScript value.
+///
+/// This only carries enough information for the code paths in this crate
+/// that pattern-match over `Value::Dimension` without inspecting the
+/// numeric payload itself.
+#[derive(Clone, Debug)]
+pub(crate) struct Number(pub(crate) f64);
+
+#[derive(Clone)]
+pub enum Value {
+    True,
+    False,
+    Null,
+    Ident(String, QuoteKind),
+    Dimension(Number, Unit),
+    Function(SassFunction),
+}
+
+impl Value {
+    pub fn bool(val: bool) -> Self {
+        if val {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+
+    /// Constructs an unquoted string value, for embedders that want to
+    /// return a plain identifier from a custom function.
+    pub fn string(s: impl Into<String>) -> Self {
+        Self::Ident(s.into(), QuoteKind::None)
+    }
+
+    pub fn is_true(&self) -> SassResult<bool> {
+        Ok(!matches!(self, Self::False | Self::Null))
+    }
+
+    pub fn kind(&self) -> SassResult<&'static str> {
+        Ok(match self {
+            Self::True | Self::False => "bool",
+            Self::Null => "null",
+            Self::Ident(..) => "string",
+            Self::Dimension(..) => "number",
+            Self::Function(..) => "function",
+        })
+    }
+
+    pub fn inspect(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::True => write!(f, "true"),
+            Self::False => write!(f, "false"),
+            Self::Null => write!(f, "null"),
+            Self::Ident(s, quotes) => match quotes {
+                QuoteKind::Double | QuoteKind::Single => write!(f, "\"{}\"", s),
+                QuoteKind::None => write!(f, "{}", s),
+            },
+            Self::Dimension(_, unit) => write!(f, "{}", unit),
+            Self::Function(func) => write!(f, "get-function(\"{}\")", func.name()),
+        }
+    }
+}
+
+/// A reference to a function, either invoked directly via `call()` or
+/// handed around as the result of `get-function()`.
+#[derive(Clone)]
+pub(crate) enum SassFunction {
+    /// A function built into the compiler, e.g. `lighten()`.
+    Builtin(Builtin, String),
+    /// A `@function` defined in the stylesheet being compiled.
+    UserDefined(Box<Function>, String),
+    /// A function registered by an embedder through `Options::add_function`,
+    /// allowing a host program to expose Rust closures as Sass functions.
+    Custom(
+        Rc<dyn Fn(CallArgs, &Scope, &Selector) -> SassResult<Value>>,
+        String,
+    ),
+}
+
+impl SassFunction {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Builtin(_, name) | Self::UserDefined(_, name) | Self::Custom(_, name) => name,
+        }
+    }
+
+    pub fn call(
+        self,
+        args: CallArgs,
+        scope: &Scope,
+        super_selector: &Selector,
+    ) -> SassResult<Value> {
+        match self {
+            Self::Builtin(func, ..) => func.call(args, scope, super_selector),
+            Self::UserDefined(func, ..) => func.call(args, scope, super_selector),
+            Self::Custom(func, ..) => func(args, scope, super_selector),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+#[test]
+fn embedder_defined_function_is_found_and_callable() {
+    let options = grass::Options::new().add_function("host-answer", |_, _, _| {
+        Ok(grass::Value::string("42"))
+    });
+
+    let exists = grass::from_string(
+        "a { b: if(function-exists(\"host-answer\"), yes, no); }".to_string(),
+        &options,
+    )
+    .expect("compiles with the custom function registered");
+    assert!(exists.contains("yes"));
+
+    let called = grass::from_string(
+        "a { b: call(get-function(\"host-answer\")); }".to_string(),
+        &options,
+    )
+    .expect("custom function is callable through get-function/call");
+    assert!(called.contains("42"));
+}
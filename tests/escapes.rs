@@ -0,0 +1,26 @@
+#[test]
+fn null_escape_becomes_replacement_character() {
+    let css = grass::from_string(".a\\0 { color: red; }".to_string(), &grass::Options::default())
+        .expect("a null escape is decoded rather than rejected");
+    assert!(css.contains('\u{FFFD}'));
+}
+
+#[test]
+fn surrogate_escape_becomes_replacement_character() {
+    let css = grass::from_string(
+        ".a\\d800 { color: red; }".to_string(),
+        &grass::Options::default(),
+    )
+    .expect("a surrogate escape is decoded rather than rejected");
+    assert!(css.contains('\u{FFFD}'));
+}
+
+#[test]
+fn out_of_range_escape_becomes_replacement_character() {
+    let css = grass::from_string(
+        ".a\\110000 { color: red; }".to_string(),
+        &grass::Options::default(),
+    )
+    .expect("an escape above the maximum code point is decoded rather than rejected");
+    assert!(css.contains('\u{FFFD}'));
+}
@@ -0,0 +1,10 @@
+#[test]
+fn get_function_errors_on_unknown_namespace() {
+    let err = grass::from_string(
+        "a { b: get-function(\"foo\", $module: \"math\"); }".to_string(),
+        &grass::Options::default(),
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("no module with the namespace \"math\""));
+}